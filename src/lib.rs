@@ -2,13 +2,16 @@
 ///
 /// Calls m!(A, B, C), m!(A, B), m!(B), and m!() for i.e. (m, A, B, C)
 /// where m is any macro, for any number of parameters.
+///
+/// Exported so downstream crates can generate tuple impls of their own traits.
+#[macro_export]
 macro_rules! smaller_tuples_too {
     ($m: ident, $ty: ident) => {
         $m!{}
         $m!{$ty}
     };
     ($m: ident, $ty: ident, $($tt: ident),*) => {
-        smaller_tuples_too!{$m, $($tt),*}
+        $crate::smaller_tuples_too!{$m, $($tt),*}
         $m!{$ty, $($tt),*}
     };
 }